@@ -8,19 +8,22 @@
     elided_lifetimes_in_paths
 )]
 
+mod offset_index;
 mod resize_observer;
 
 use core::fmt;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::fmt::Display;
+use std::ops::Range;
 use std::rc::Rc;
 
 use gloo_timers::callback::Timeout;
+use offset_index::OffsetIndex;
 use resize_observer::{ObservedElement, ResizeObserver};
 use wasm_bindgen::prelude::wasm_bindgen;
 use wasm_bindgen::JsCast;
 use web_sys::Element;
-use yew::html::IntoPropValue;
+use yew::html::{IntoPropValue, Scope};
 use yew::prelude::*;
 
 /// A wrapper around the method generating individual items in the list.
@@ -53,18 +56,35 @@ impl VirtualList {
     pub fn item_gen(gen: impl 'static + Fn(usize) -> Html) -> ItemGenerator { ItemGenerator { gen: Rc::new(gen) } }
 }
 
-/// The height of each items, usually given in pixels.
+/// The size of each item along the list's [`Axis`], usually given in pixels
+/// but also expressible in units relative to font size or viewport, same as
+/// CSS.
 #[derive(Debug, PartialEq)]
 #[non_exhaustive]
 pub enum ItemSize {
-    /// A height in pixels
+    /// A size in pixels.
     Pixels(usize),
+    /// A size in multiples of the root element's font size, like CSS `rem`.
+    Rem(f64),
+    /// A size in multiples of the host list's own font size, like CSS `em`.
+    Em(f64),
+    /// A percentage of the viewport's extent along the list's [`Axis`] (its
+    /// width when [`Axis::Horizontal`], its height when [`Axis::Vertical`]),
+    /// like CSS `vw`/`vh`.
+    ViewportPercent(f64),
 }
 
 impl ItemSize {
-    fn as_scroll_size(&self) -> i32 {
+    /// Resolve this size to a pixel value. `host` is the mounted list
+    /// element, used to look up its computed font size for [`Self::Em`],
+    /// which falls back to a conventional default before the host has
+    /// mounted.
+    fn resolve_px(&self, host: Option<&Element>, axis: Axis) -> f64 {
         match self {
-            Self::Pixels(pxs) => (*pxs).try_into().unwrap(),
+            Self::Pixels(pxs) => *pxs as f64,
+            Self::Rem(rem) => rem * root_font_size_px(),
+            Self::Em(em) => em * host.map_or(DEFAULT_FONT_SIZE_PX, computed_font_size_px),
+            Self::ViewportPercent(percent) => percent / 100.0 * viewport_extent_px(axis),
         }
     }
 }
@@ -77,6 +97,11 @@ impl Display for ItemSize {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Pixels(pxs) => write!(f, "{pxs}px"),
+            Self::Rem(rem) => write!(f, "{rem}rem"),
+            Self::Em(em) => write!(f, "{em}em"),
+            // `vw`/`vh` depend on the list's axis, which isn't available
+            // here, so this can't be rendered as a single CSS unit token.
+            Self::ViewportPercent(percent) => write!(f, "{percent}% of viewport"),
         }
     }
 }
@@ -87,10 +112,176 @@ impl std::ops::Mul<&'_ ItemSize> for usize {
     fn mul(self, rhs: &ItemSize) -> Self::Output {
         match rhs {
             ItemSize::Pixels(pxs) => ItemSize::Pixels(self * pxs),
+            ItemSize::Rem(rem) => ItemSize::Rem(self as f64 * rem),
+            ItemSize::Em(em) => ItemSize::Em(self as f64 * em),
+            ItemSize::ViewportPercent(percent) => ItemSize::ViewportPercent(self as f64 * percent),
         }
     }
 }
 
+/// The conventional default browser font size, in pixels, used when a
+/// computed font size can't be determined (e.g. before the host mounts).
+const DEFAULT_FONT_SIZE_PX: f64 = 16.0;
+
+/// The viewport's extent along `axis`, in pixels: its width when
+/// [`Axis::Horizontal`], its height when [`Axis::Vertical`].
+fn viewport_extent_px(axis: Axis) -> f64 {
+    let Some(window) = web_sys::window() else { return 0.0 };
+    let extent = match axis {
+        Axis::Vertical => window.inner_height(),
+        Axis::Horizontal => window.inner_width(),
+    };
+    extent.ok().and_then(|value| value.as_f64()).unwrap_or(0.0)
+}
+
+/// The document root's computed font size, in pixels, used to resolve
+/// [`ItemSize::Rem`].
+fn root_font_size_px() -> f64 {
+    web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|doc| doc.document_element())
+        .map_or(DEFAULT_FONT_SIZE_PX, |root| computed_font_size_px(&root))
+}
+
+/// `el`'s computed font size, in pixels, used to resolve [`ItemSize::Em`].
+fn computed_font_size_px(el: &Element) -> f64 {
+    web_sys::window()
+        .and_then(|window| window.get_computed_style(el).ok().flatten())
+        .and_then(|style| style.get_property_value("font-size").ok())
+        .and_then(|value| value.trim_end_matches("px").parse().ok())
+        .unwrap_or(DEFAULT_FONT_SIZE_PX)
+}
+
+/// Where to align a target item within the viewport when scrolling to it
+/// imperatively, see [`ScrollToHandle::scroll_to`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alignment {
+    /// Align the item's leading edge with the start of the viewport.
+    Start,
+    /// Center the item within the viewport.
+    Center,
+    /// Align the item's trailing edge with the end of the viewport.
+    End,
+}
+
+/// An imperative handle to scroll a [`VirtualList`] to a given item from
+/// outside its own component tree, e.g. for "jump to search result".
+///
+/// Create one with [`ScrollToHandle::default`] and pass it to
+/// [`VirtualListProps::scroll_handle`]; [`ScrollToHandle::scroll_to`] is a
+/// no-op until the handle has been bound to a mounted list.
+#[derive(Clone, Default)]
+pub struct ScrollToHandle(Rc<RefCell<Option<Scope<VirtualList>>>>);
+
+impl fmt::Debug for ScrollToHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScrollToHandle").finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for ScrollToHandle {
+    // A handle is a sink, not a value to compare; treat it like `NodeRef`
+    // and never force a re-render because of it.
+    fn eq(&self, _other: &Self) -> bool { true }
+}
+
+impl ScrollToHandle {
+    /// Scroll so that the item at `index` is aligned within the viewport as
+    /// specified by `align`. A no-op if the handle isn't bound to a mounted
+    /// [`VirtualList`] yet.
+    pub fn scroll_to(&self, index: usize, align: Alignment) {
+        if let Some(scope) = self.0.borrow().as_ref() {
+            scope.send_message(VirtualListMsg(ScrollMsg::ScrollTo(index, align)));
+        }
+    }
+}
+
+/// Which direction a [`VirtualList`] lays out and scrolls its items, see
+/// [`VirtualListProps::axis`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Axis {
+    /// Items stack top-to-bottom and the list scrolls vertically. This is
+    /// the default.
+    #[default]
+    Vertical,
+    /// Items stack left-to-right and the list scrolls horizontally.
+    ///
+    /// ### Gotcha
+    ///
+    /// This only changes the scroll math (`scroll_left`, `client_width`,
+    /// the `ResizeObserver`'s measured width, …). It does not itself make
+    /// anything lay out in a row: the host `div` and item wrappers are
+    /// plain block elements. To get a horizontal list, also give the host
+    /// (via [`VirtualListProps::classes`]) `display: flex; flex-direction:
+    /// row;`, and give items (via [`VirtualListProps::item_classes`])
+    /// `flex-shrink: 0;` or similar so they don't collapse to the
+    /// container's width.
+    Horizontal,
+}
+
+/// Read the host element's current scroll position along `axis`.
+fn element_scroll_offset(host: &Element, axis: Axis) -> i32 {
+    match axis {
+        Axis::Vertical => host.scroll_top(),
+        Axis::Horizontal => host.scroll_left(),
+    }
+}
+
+/// Set the host element's scroll position along `axis`.
+fn set_element_scroll_offset(host: &Element, axis: Axis, value: i32) {
+    match axis {
+        Axis::Vertical => host.set_scroll_top(value),
+        Axis::Horizontal => host.set_scroll_left(value),
+    }
+}
+
+/// `el`'s content-box extent along `axis`, in pixels: its height when
+/// [`Axis::Vertical`], its width when [`Axis::Horizontal`].
+fn element_client_extent(el: &Element, axis: Axis) -> i32 {
+    match axis {
+        Axis::Vertical => el.client_height(),
+        Axis::Horizontal => el.client_width(),
+    }
+}
+
+/// A mouse event's client position along `axis`.
+fn mouse_client_pos(e: &MouseEvent, axis: Axis) -> i32 {
+    match axis {
+        Axis::Vertical => e.client_y(),
+        Axis::Horizontal => e.client_x(),
+    }
+}
+
+/// Which edge of the list's content stays anchored as items are appended or
+/// the viewport is resized, see [`VirtualListProps::orientation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Orientation {
+    /// The list grows from a fixed leading edge. This is the default.
+    #[default]
+    Top,
+    /// For content that grows at its trailing edge, e.g. a chat or log view.
+    /// When the props change (e.g. items are appended) while the user is
+    /// scrolled to the end, the viewport is kept glued to the end; otherwise
+    /// the content at the viewport's leading edge is kept in place across
+    /// the change via scroll anchoring. The initial scroll position is not
+    /// moved to the end, and size corrections reported outside of a props
+    /// change are not anchored.
+    Bottom,
+}
+
+/// Where to re-pin the viewport after a props change while
+/// [`Orientation::Bottom`] is active, captured just before the change is
+/// applied by [`VirtualList::changed`].
+#[derive(Debug, Clone, Copy)]
+enum ScrollAnchor {
+    /// The user was scrolled to the end; keep following it.
+    End,
+    /// The user had item `index` at the leading edge of the viewport,
+    /// `intra` pixels into it; keep that same content pixel under the
+    /// leading edge.
+    Item { index: usize, intra: f64 },
+}
+
 #[wasm_bindgen]
 extern "C" {
     type PositionedElementDuck;
@@ -141,6 +332,106 @@ fn scroll_item_wrapper(props: &ScrollWrapperProps) -> Html {
     }
 }
 
+/// State for a [`Scrollbar`], modeled after ratatui's `ScrollbarState`:
+/// the total content length, the viewport length and the current scroll
+/// position, all in the same pixel units.
+#[derive(Properties, PartialEq)]
+struct ScrollbarProps {
+    content_length: f64,
+    viewport_length: f64,
+    position: f64,
+    axis: Axis,
+    onscroll: Callback<i32>,
+}
+
+/// A draggable overlay scrollbar, reflecting true content size even while
+/// most items are still estimated from `height_prior`. Gated behind
+/// [`VirtualListProps::scrollbar`] for users who'd rather keep the native
+/// scrollbar of the host `div`.
+#[function_component(Scrollbar)]
+fn scrollbar(props: &ScrollbarProps) -> Html {
+    let track_ref = use_node_ref();
+    let drag_start = use_state(|| Option::<(i32, f64)>::None);
+
+    let content_length = props.content_length.max(props.viewport_length).max(1.0);
+    let scrollable = (content_length - props.viewport_length).max(0.0);
+    let thumb_fraction = (props.viewport_length / content_length).clamp(0.02, 1.0);
+    let thumb_offset_fraction = if scrollable > 0.0 {
+        (props.position / scrollable).clamp(0.0, 1.0) * (1.0 - thumb_fraction)
+    } else {
+        0.0
+    };
+
+    let onmousedown = {
+        let drag_start = drag_start.clone();
+        let position = props.position;
+        let axis = props.axis;
+        Callback::from(move |e: MouseEvent| {
+            e.prevent_default();
+            drag_start.set(Some((mouse_client_pos(&e, axis), position)));
+        })
+    };
+    let ondrag = {
+        let track_ref = track_ref.clone();
+        let drag_start = drag_start.clone();
+        let onscroll = props.onscroll.clone();
+        let axis = props.axis;
+        Callback::from(move |e: MouseEvent| {
+            if let Some((start_pos, start_position)) = *drag_start {
+                let track_extent = track_ref
+                    .cast::<Element>()
+                    .map_or(1.0, |el| f64::from(element_client_extent(&el, axis)))
+                    .max(1.0);
+                let delta = f64::from(mouse_client_pos(&e, axis) - start_pos) * scrollable / track_extent;
+                onscroll.emit((start_position + delta).clamp(0.0, scrollable) as i32);
+            }
+        })
+    };
+    let ondragend = {
+        let drag_start = drag_start.clone();
+        Callback::from(move |_: MouseEvent| drag_start.set(None))
+    };
+
+    // While dragging, a fixed full-viewport overlay captures mouse movement
+    // even if the cursor leaves the thumb or the track.
+    let drag_overlay = drag_start.is_some().then(|| {
+        html! {
+            <div
+                style="position: fixed; inset: 0; cursor: grabbing;"
+                onmousemove={ondrag.clone()}
+                onmouseup={ondragend.clone()}
+                onmouseleave={ondragend.clone()}
+            />
+        }
+    });
+
+    let track_style = match props.axis {
+        Axis::Vertical => "position: absolute; top: 0; right: 0; bottom: 0; width: 10px;",
+        Axis::Horizontal => "position: absolute; left: 0; right: 0; bottom: 0; height: 10px;",
+    };
+    let thumb_style = match props.axis {
+        Axis::Vertical => format!(
+            "position: absolute; top: {:.3}%; height: {:.3}%; width: 100%; cursor: grab;",
+            thumb_offset_fraction * 100.0,
+            thumb_fraction * 100.0,
+        ),
+        Axis::Horizontal => format!(
+            "position: absolute; left: {:.3}%; width: {:.3}%; height: 100%; cursor: grab;",
+            thumb_offset_fraction * 100.0,
+            thumb_fraction * 100.0,
+        ),
+    };
+
+    html! {
+        <>
+        <div ref={&track_ref} class="yew-virtualized-scrollbar-track" style={track_style}>
+            <div onmousedown={onmousedown} class="yew-virtualized-scrollbar-thumb" style={thumb_style} />
+        </div>
+        {for drag_overlay}
+        </>
+    }
+}
+
 /// Scroll state as reflected during rendering
 #[derive(Default, Debug)]
 struct EffectiveScrollState {
@@ -153,109 +444,224 @@ struct EffectiveScrollState {
 /// Backing scroll state, as source of truth for item sizes, etc.
 #[derive(Debug)]
 struct BackingScrollState {
-    element_sizes: RefCell<Vec<f64>>,
+    element_sizes: RefCell<OffsetIndex>,
+    /// The list's current main axis, kept in sync with
+    /// [`VirtualListProps::axis`] so the `ResizeObserver` callback (which
+    /// only has access to `shared`, not the component's props) knows
+    /// whether to measure a resized item's height or width.
+    axis: Cell<Axis>,
     trigger_update: Callback<()>,
 }
 
 #[derive(Debug)]
 struct ScrollManager {
-    host_height: i32,
-    scroll_top: i32,
+    host_extent: i32,
+    scroll_offset: i32,
+    /// Signed scroll speed in pixels/millisecond, as of the last `update`;
+    /// positive means scrolling toward later items.
+    velocity: f64,
+    last_update_time: f64,
     observer: Rc<ResizeObserver>,
     shared: Rc<BackingScrollState>,
     scroll_state: EffectiveScrollState,
 }
 
+/// The current time in milliseconds, for scroll velocity tracking.
+fn now_ms() -> f64 {
+    web_sys::window()
+        .and_then(|window| window.performance())
+        .map_or(0.0, |performance| performance.now())
+}
+
 impl ScrollManager {
     fn new(trigger_update: Callback<()>) -> Self {
         let shared = {
             let trigger_update = trigger_update.clone();
             Rc::new(BackingScrollState {
                 element_sizes: RefCell::default(),
+                axis: Cell::new(Axis::default()),
                 trigger_update,
             })
         };
         let observer = {
             let shared = shared.clone();
             Rc::new(ResizeObserver::new(move |change_entries| {
+                let axis = shared.axis.get();
                 let mut element_sizes = shared.element_sizes.borrow_mut();
                 for change in change_entries {
                     let pos = change.target().unchecked_ref::<PositionedElementDuck>().pos();
-                    element_sizes[pos] = change.content_rect().height();
+                    let rect = change.content_rect();
+                    let size = match axis {
+                        Axis::Vertical => rect.height(),
+                        Axis::Horizontal => rect.width(),
+                    };
+                    element_sizes.set(pos, size);
                 }
                 drop(element_sizes);
                 trigger_update.emit(());
             }))
         };
         ScrollManager {
-            host_height: 0,
-            scroll_top: 0,
+            host_extent: 0,
+            scroll_offset: 0,
+            velocity: 0.0,
+            last_update_time: now_ms(),
             observer,
             shared,
             scroll_state: Default::default(),
         }
     }
 
-    fn mounted(&mut self, host: Element) {
-        let height = host.client_height();
-        self.host_height = height;
+    fn mounted(&mut self, host: Element, axis: Axis) {
+        self.shared.axis.set(axis);
+        self.host_extent = element_client_extent(&host, axis);
         self.shared.trigger_update.emit(());
     }
 
-    fn update(&mut self, scroll_top: i32) {
-        if self.scroll_top != scroll_top {
-            self.scroll_top = scroll_top;
+    fn update(&mut self, scroll_offset: i32) {
+        if self.scroll_offset != scroll_offset {
+            let now = now_ms();
+            let dt = now - self.last_update_time;
+            if dt > 0.0 {
+                self.velocity = f64::from(scroll_offset - self.scroll_offset) / dt;
+            }
+            self.last_update_time = now;
+            self.scroll_offset = scroll_offset;
             self.shared.trigger_update.emit(());
         }
     }
 
-    fn regenerate_scroll_state(&mut self, props: &VirtualListProps) {
-        self.scroll_state = self.generate_scroll_state(props);
+    /// Split `overdraw` pixels of render buffer into a `(before, after)`
+    /// pair biased toward the direction of travel, so a fast fling renders
+    /// ahead of the viewport while the trailing edge keeps only a minimal
+    /// safety margin. With no recorded scroll movement the split is
+    /// symmetric, and slow scrolling keeps it close to symmetric. The
+    /// velocity is that of the last scroll movement; it is not reset when
+    /// scrolling stops.
+    fn overdraw_split(&self, overdraw: f64) -> (f64, f64) {
+        if overdraw <= 0.0 {
+            return (0.0, 0.0);
+        }
+        /// Scroll speed (px/ms) at which the bias saturates.
+        const FULL_BIAS_SPEED: f64 = 2.0;
+        /// Smallest fraction of the buffer kept on the trailing edge, even
+        /// during a fast fling.
+        const MIN_TRAILING_FRACTION: f64 = 0.15;
+
+        let bias = (self.velocity.abs() / FULL_BIAS_SPEED).min(1.0);
+        let trailing_fraction = 0.5 + bias * (MIN_TRAILING_FRACTION - 0.5);
+        let trailing = overdraw * trailing_fraction;
+        let leading = overdraw - trailing;
+
+        if self.velocity > 0.0 {
+            (trailing, leading)
+        } else {
+            (leading, trailing)
+        }
     }
 
-    fn generate_scroll_state(&self, props: &VirtualListProps) -> EffectiveScrollState {
-        let item_height = props.height_prior.as_scroll_size();
-        // take care of some state change
-        {
-            let mut element_sizes = self.shared.element_sizes.borrow_mut();
-            element_sizes.resize(props.item_count, item_height.into());
+    fn regenerate_scroll_state(&mut self, props: &VirtualListProps, host: Option<&Element>) {
+        self.shared.axis.set(props.axis);
+        // Re-measure every time, not just at mount: if `axis` flips at
+        // runtime, the previously measured extent is along the wrong
+        // dimension (a stale `client_height` read as if it were
+        // `client_width`, or vice versa).
+        if let Some(host) = host {
+            self.host_extent = element_client_extent(host, props.axis);
         }
+        self.scroll_state = self.generate_scroll_state(props, host);
+    }
+
+    /// The range of item indices currently instantiated on screen.
+    fn visible_range(&self) -> Range<usize> { self.scroll_state.first_idx..self.scroll_state.past_last_idx }
 
+    /// Compute the `scroll_offset` that aligns item `index` within the
+    /// viewport as requested by `align`, using the current (possibly still
+    /// estimated) offset index.
+    fn target_scroll_offset(&self, index: usize, align: Alignment) -> i32 {
         let element_sizes = self.shared.element_sizes.borrow();
-        // TODO: depend on item_height and scroll speed?
-        const EXTRA_BUFFER: usize = 5;
-        // TODO: rework to range-query datastructure
-        let mut before_ring_buffered: [f64; EXTRA_BUFFER] = [0.0; EXTRA_BUFFER];
-        let mut before_ring_buff_idx = 0usize;
-        let mut first_idx = props.item_count;
-
-        let mut passed_height = 0f64;
-        for (i, i_size) in element_sizes.iter().enumerate() {
-            let height_before = passed_height;
-            passed_height += i_size;
-            if passed_height >= self.scroll_top.into() {
-                first_idx = i;
-                break;
-            }
+        if element_sizes.len() == 0 {
+            return 0;
+        }
+        let index = index.min(element_sizes.len() - 1);
+        let offset = element_sizes.prefix_sum(index);
+        let item_size = element_sizes.get(index);
+        let target = match align {
+            Alignment::Start => offset,
+            Alignment::Center => offset - (f64::from(self.host_extent) - item_size) / 2.0,
+            Alignment::End => offset - f64::from(self.host_extent) + item_size,
+        };
+        target.max(0.0) as i32
+    }
+
+    /// Total extent of the content along the main axis, as tracked by the
+    /// offset index.
+    fn content_length(&self) -> f64 { self.shared.element_sizes.borrow().total() }
+
+    /// Whether the viewport is currently scrolled to the end of the content.
+    fn at_end(&self) -> bool {
+        let total = self.shared.element_sizes.borrow().total();
+        f64::from(self.scroll_offset + self.host_extent) >= total - 1.0
+    }
+
+    /// The `scroll_offset` that pins the viewport to the end of the content.
+    fn end_scroll_offset(&self) -> i32 {
+        let total = self.shared.element_sizes.borrow().total();
+        (total - f64::from(self.host_extent)).max(0.0) as i32
+    }
+
+    /// Capture the item currently at the leading edge of the viewport, and
+    /// how far into it the viewport's leading edge falls, so that
+    /// [`Self::scroll_offset_for_anchor`] can later restore the same visual
+    /// position.
+    fn scroll_anchor(&self) -> ScrollAnchor {
+        if self.at_end() {
+            return ScrollAnchor::End;
+        }
+        let element_sizes = self.shared.element_sizes.borrow();
+        let (index, offset) = element_sizes.lower_bound(self.scroll_offset.into());
+        ScrollAnchor::Item {
+            index,
+            intra: f64::from(self.scroll_offset) - offset,
+        }
+    }
 
-            before_ring_buffered[before_ring_buff_idx as usize] = height_before;
-            before_ring_buff_idx += 1;
-            before_ring_buff_idx %= before_ring_buffered.len();
+    /// The `scroll_offset` that puts item `index`'s leading edge `intra`
+    /// pixels above the viewport's leading edge.
+    fn scroll_offset_for_anchor(&self, index: usize, intra: f64) -> i32 {
+        let element_sizes = self.shared.element_sizes.borrow();
+        if element_sizes.len() == 0 {
+            return 0;
         }
-        let first_idx = first_idx.saturating_sub(EXTRA_BUFFER).min(props.item_count);
-        let hidden_before = before_ring_buffered[first_idx % EXTRA_BUFFER];
-
-        let mut past_last_idx = props.item_count;
-        let mut passed_height = hidden_before;
-        for (i, i_size) in element_sizes.iter().enumerate().skip(first_idx) {
-            passed_height += i_size;
-            if passed_height >= (self.scroll_top + self.host_height).into() {
-                past_last_idx = i.saturating_add(1 + EXTRA_BUFFER);
-                break;
+        let index = index.min(element_sizes.len() - 1);
+        let offset = element_sizes.prefix_sum(index);
+        (offset + intra).max(0.0) as i32
+    }
+
+    fn generate_scroll_state(&self, props: &VirtualListProps, host: Option<&Element>) -> EffectiveScrollState {
+        // take care of some state change
+        {
+            let mut element_sizes = self.shared.element_sizes.borrow_mut();
+            // Resolving a relative `height_prior` can force a style
+            // recalculation (`getComputedStyle`), so only pay for it when
+            // the item count actually changed and a prior size is needed;
+            // this runs on every scroll-driven update otherwise.
+            if element_sizes.len() != props.item_count {
+                let item_size = props.height_prior.resolve_px(host, props.axis);
+                element_sizes.resize(props.item_count, item_size);
             }
         }
-        let past_last_idx = past_last_idx.min(props.item_count);
-        let hidden_after: f64 = element_sizes[past_last_idx..].iter().sum();
+
+        let element_sizes = self.shared.element_sizes.borrow();
+        let (before_buffer, after_buffer) = self.overdraw_split(props.overdraw);
+
+        let (first_idx, _) = element_sizes.lower_bound((f64::from(self.scroll_offset) - before_buffer).max(0.0));
+        let hidden_before = element_sizes.prefix_sum(first_idx);
+
+        let (past_last_idx, _) =
+            element_sizes.lower_bound(f64::from(self.scroll_offset + self.host_extent) + after_buffer);
+        let past_last_idx = past_last_idx.saturating_add(1).min(props.item_count);
+        let hidden_after = element_sizes.total() - element_sizes.prefix_sum(past_last_idx);
 
         EffectiveScrollState {
             first_idx,
@@ -273,6 +679,11 @@ impl ScrollManager {
             hidden_after,
         } = self.scroll_state;
 
+        let extent_prop = match props.axis {
+            Axis::Vertical => "height",
+            Axis::Horizontal => "width",
+        };
+
         let items = (first_idx..past_last_idx).map(|i| {
             let item = props.items.emit(i);
             html! {
@@ -284,12 +695,12 @@ impl ScrollManager {
 
         html! {
             <>
-            <div key="pre" style={format!("height: {hidden_before}px;")}>
+            <div key="pre" style={format!("{extent_prop}: {hidden_before}px;")}>
             </div>
             <div key="wrap" style={"display: contents;"}>
             {for items}
             </div>
-            <div key="post" style={format!("height: {hidden_after}px;")}>
+            <div key="post" style={format!("{extent_prop}: {hidden_after}px;")}>
             </div>
             </>
         }
@@ -306,9 +717,15 @@ pub struct VirtualListProps {
     /// screen take up scroll space and are lazily instantiated when the user
     /// scrolls to them later.
     pub item_count: usize,
-    /// An approximate height for items that haven't been rendered, yet, but
-    /// should still take up scroll space. After the first render of an
-    /// item, the height will be adjusted automatically and measured.
+    /// Which direction the list lays out and scrolls its items. See
+    /// [`Axis`] (and its [`Axis::Horizontal`] Gotcha); defaults to
+    /// [`Axis::Vertical`].
+    #[prop_or_default]
+    pub axis: Axis,
+    /// An approximate size, along [`Self::axis`], for items that haven't
+    /// been rendered, yet, but should still take up scroll space. After the
+    /// first render of an item, the size will be adjusted automatically and
+    /// measured.
     ///
     /// Setting this to an inaccurate value will mis-represent the remaining
     /// scroll distance, but cause no other ill effects.
@@ -326,6 +743,44 @@ pub struct VirtualListProps {
     /// Usually, you don't need to supply this property.
     #[prop_or_default]
     pub item_classes: Classes,
+    /// An imperative handle letting callers jump to a specific item with
+    /// [`ScrollToHandle::scroll_to`]. Usually created once alongside the
+    /// list and kept in the parent's state, much like a [`NodeRef`].
+    #[prop_or_default]
+    pub scroll_handle: Option<ScrollToHandle>,
+    /// Which edge of the content is anchored as items are added. See
+    /// [`Orientation`]; defaults to [`Orientation::Top`].
+    #[prop_or_default]
+    pub orientation: Orientation,
+    /// Fired whenever the range of rendered item indices changes, e.g. to
+    /// lazily fetch data around the visible window.
+    #[prop_or_default]
+    pub onvisible_change: Callback<Range<usize>>,
+    /// Fired once the rendered window reaches `item_count`, as a
+    /// convenience for triggering infinite loading without inspecting the
+    /// range from [`Self::onvisible_change`] directly.
+    #[prop_or_default]
+    pub onreached_end: Callback<()>,
+    /// How many pixels beyond the viewport to instantiate items for, so
+    /// scrolling doesn't show blank frames while new items mount. The
+    /// buffer is biased toward the direction of scroll travel, with a
+    /// larger leading and a minimal trailing margin during a fast fling.
+    #[prop_or(150.0)]
+    pub overdraw: f64,
+    /// Render a draggable overlay scrollbar inside the host `div`,
+    /// reflecting true content size and scroll position.
+    ///
+    /// ### Gotcha
+    ///
+    /// This does not hide the host's native scrollbar; with `overflow:
+    /// auto`/`scroll` set via [`Self::classes`] as instructed there, the
+    /// native scrollbar and this overlay will both be visible at once. If
+    /// that's not wanted, hide the native one yourself via `classes`, e.g.
+    /// `scrollbar-width: none;` plus a `::-webkit-scrollbar { display:
+    /// none; }` rule (inline styles can't reach that pseudo-element, so a
+    /// stylesheet class is required).
+    #[prop_or_default]
+    pub scrollbar: bool,
 }
 
 fn debounced<E: 'static>(millis: u32, cb: Callback<E>) -> Callback<E> {
@@ -352,6 +807,8 @@ pub struct VirtualListMsg(ScrollMsg);
 enum ScrollMsg {
     Scroll(Event),
     Update,
+    ScrollTo(usize, Alignment),
+    JumpTo(i32),
 }
 
 /// A virtalized list, rendering only items that are also shown on screen to the
@@ -382,7 +839,56 @@ enum ScrollMsg {
 pub struct VirtualList {
     manager: ScrollManager,
     onscroll: Callback<Event>,
+    onscrollbar_drag: Callback<i32>,
     host_ref: NodeRef,
+    pending_scroll_to: Option<(usize, Alignment)>,
+    pending_anchor: Option<ScrollAnchor>,
+    visible_range: Range<usize>,
+}
+
+impl VirtualList {
+    /// Apply (or re-apply) a pending [`ScrollToHandle::scroll_to`] request,
+    /// nudging `scroll_offset` if the target item's offset has moved since
+    /// the request was made, e.g. because its estimated `height_prior` was
+    /// corrected by the `ResizeObserver` after it mounted.
+    fn apply_pending_scroll_to(&mut self, axis: Axis) {
+        if let Some((index, align)) = self.pending_scroll_to {
+            let target = self.manager.target_scroll_offset(index, align);
+            if (target - self.manager.scroll_offset).abs() <= 1 {
+                // Still aligned: keep the request pending until the `scroll`
+                // event of the earlier DOM write clears it, so a later size
+                // correction can still nudge the position.
+                return;
+            }
+            if let Some(host) = self.host_ref.cast::<Element>() {
+                set_element_scroll_offset(&host, axis, target);
+            }
+            self.manager.update(target);
+        }
+    }
+
+    /// Re-pin the viewport according to a [`ScrollAnchor`] captured just
+    /// before the props change that triggered the current update.
+    fn apply_scroll_anchor(&mut self, anchor: ScrollAnchor, axis: Axis) {
+        let target = match anchor {
+            ScrollAnchor::End => self.manager.end_scroll_offset(),
+            ScrollAnchor::Item { index, intra } => self.manager.scroll_offset_for_anchor(index, intra),
+        };
+        if let Some(host) = self.host_ref.cast::<Element>() {
+            set_element_scroll_offset(&host, axis, target);
+        }
+        self.manager.update(target);
+    }
+
+    /// Jump directly to a `scroll_offset`, as requested by dragging the
+    /// overlay scrollbar.
+    fn jump_to_scroll_offset(&mut self, target: i32, axis: Axis) {
+        self.pending_scroll_to = None;
+        if let Some(host) = self.host_ref.cast::<Element>() {
+            set_element_scroll_offset(&host, axis, target);
+        }
+        self.manager.update(target);
+    }
 }
 
 impl Component for VirtualList {
@@ -394,51 +900,106 @@ impl Component for VirtualList {
         let manager = ScrollManager::new(trigger_update);
         let onscroll = ctx.link().callback(|scroll| VirtualListMsg(ScrollMsg::Scroll(scroll)));
         let onscroll = debounced(50, onscroll);
+        let onscrollbar_drag = ctx.link().callback(|target| VirtualListMsg(ScrollMsg::JumpTo(target)));
         let host_ref = NodeRef::default();
+        if let Some(handle) = &ctx.props().scroll_handle {
+            *handle.0.borrow_mut() = Some(ctx.link().clone());
+        }
         Self {
             manager,
             onscroll,
+            onscrollbar_drag,
             host_ref,
+            pending_scroll_to: None,
+            pending_anchor: None,
+            visible_range: 0..0,
         }
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
         match msg {
             VirtualListMsg(ScrollMsg::Scroll(scroll)) => {
+                self.pending_scroll_to = None;
                 let el = scroll.target_dyn_into::<web_sys::Element>().unwrap();
-                let scroll_top = el.scroll_top();
-                self.manager.update(scroll_top);
+                let scroll_offset = element_scroll_offset(&el, ctx.props().axis);
+                self.manager.update(scroll_offset);
                 // triggered indirectly via Message::Update
                 false
             }
             VirtualListMsg(ScrollMsg::Update) => {
-                self.manager.regenerate_scroll_state(ctx.props());
+                self.manager.regenerate_scroll_state(ctx.props(), self.host_ref.cast::<Element>().as_ref());
+                if self.pending_scroll_to.is_some() {
+                    self.apply_pending_scroll_to(ctx.props().axis);
+                } else if let Some(anchor) = self.pending_anchor.take() {
+                    self.apply_scroll_anchor(anchor, ctx.props().axis);
+                }
+                let visible_range = self.manager.visible_range();
+                if visible_range != self.visible_range {
+                    self.visible_range = visible_range.clone();
+                    ctx.props().onvisible_change.emit(visible_range.clone());
+                    if visible_range.end >= ctx.props().item_count {
+                        ctx.props().onreached_end.emit(());
+                    }
+                }
                 true
             }
+            VirtualListMsg(ScrollMsg::ScrollTo(index, align)) => {
+                self.pending_scroll_to = Some((index, align));
+                let scroll_offset_before = self.manager.scroll_offset;
+                self.apply_pending_scroll_to(ctx.props().axis);
+                if self.manager.scroll_offset == scroll_offset_before {
+                    // Already there: no DOM write means no `scroll` event will
+                    // fire to clear this, so clear it ourselves.
+                    self.pending_scroll_to = None;
+                }
+                // triggered indirectly via Message::Update
+                false
+            }
+            VirtualListMsg(ScrollMsg::JumpTo(target)) => {
+                self.jump_to_scroll_offset(target, ctx.props().axis);
+                // triggered indirectly via Message::Update
+                false
+            }
         }
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
         let props = ctx.props();
         let contents = self.manager.generate_contents(props);
+        let scrollbar = props.scrollbar.then(|| {
+            html! {
+                <Scrollbar
+                    content_length={self.manager.content_length()}
+                    viewport_length={f64::from(self.manager.host_extent)}
+                    position={f64::from(self.manager.scroll_offset)}
+                    axis={props.axis}
+                    onscroll={&self.onscrollbar_drag}
+                />
+            }
+        });
+        let host_style = if props.scrollbar { "position: relative;" } else { "" };
 
         html! {
-            <div ref={&self.host_ref} class={props.classes.clone()} onscroll={&self.onscroll}>
+            <div ref={&self.host_ref} class={props.classes.clone()} style={host_style} onscroll={&self.onscroll}>
                 {contents}
+                {for scrollbar}
             </div>
         }
     }
 
     fn changed(&mut self, ctx: &Context<Self>) -> bool {
+        if ctx.props().orientation == Orientation::Bottom {
+            self.pending_anchor = Some(self.manager.scroll_anchor());
+        }
         ctx.link().send_message(VirtualListMsg(ScrollMsg::Update));
         // triggered indirectly via Message::Update
         false
     }
 
-    fn rendered(&mut self, _: &Context<Self>, first_render: bool) {
+    fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
         if first_render {
             let host = self.host_ref.cast::<Element>().unwrap();
-            self.manager.mounted(host);
+            self.manager.mounted(host, ctx.props().axis);
         }
     }
 }
@@ -0,0 +1,210 @@
+//! A Fenwick tree (binary indexed tree) over per-item sizes.
+//!
+//! Backs [`crate::ScrollManager`]'s notion of "where is item `i` along the
+//! scroll axis", answering both a point update (an item's measured size
+//! changed) and a prefix-sum / lower-bound query (which item sits at a given
+//! scroll offset) in `O(log n)` instead of rescanning every item.
+
+#[derive(Debug, Default)]
+pub(crate) struct OffsetIndex {
+    /// Raw per-item sizes, 0-indexed; `sizes[i]` is the size of item `i`.
+    sizes: Vec<f64>,
+    /// Fenwick tree over `sizes`, 1-indexed; `tree[0]` is unused padding.
+    tree: Vec<f64>,
+}
+
+impl OffsetIndex {
+    /// Grow or shrink the index to `item_count` entries, seeding newly added
+    /// entries with `default_size`.
+    pub(crate) fn resize(&mut self, item_count: usize, default_size: f64) {
+        match item_count.cmp(&self.sizes.len()) {
+            std::cmp::Ordering::Less => {
+                self.sizes.truncate(item_count);
+                self.rebuild();
+            }
+            std::cmp::Ordering::Greater => {
+                for _ in self.sizes.len()..item_count {
+                    self.push(default_size);
+                }
+            }
+            std::cmp::Ordering::Equal => {}
+        }
+    }
+
+    fn rebuild(&mut self) {
+        self.tree.clear();
+        self.tree.resize(self.sizes.len() + 1, 0.0);
+        for pos in 0..self.sizes.len() {
+            let size = self.sizes[pos];
+            self.add_to_tree(pos, size);
+        }
+    }
+
+    /// Append one more item of `size` at the end, extending the Fenwick
+    /// tree by a single node in `O(log n)` rather than rebuilding it from
+    /// scratch.
+    ///
+    /// A BIT node's range depends only on its own index, so an append never
+    /// has to touch any existing node — it only has to fold the already-built
+    /// nodes that now fall *under* the newly exposed node `i` into its value,
+    /// via the same prefix sum those nodes already encode.
+    fn push(&mut self, size: f64) {
+        if self.tree.is_empty() {
+            self.tree.push(0.0); // unused padding at index 0
+        }
+        let pos = self.sizes.len();
+        self.sizes.push(size);
+        let i = pos + 1;
+        self.tree.push(0.0);
+        let low = i & i.wrapping_neg();
+        let absorbed = self.prefix_sum(i - 1) - self.prefix_sum(i - low);
+        self.tree[i] = size + absorbed;
+    }
+
+    fn add_to_tree(&mut self, pos: usize, delta: f64) {
+        let mut i = pos + 1;
+        while i < self.tree.len() {
+            self.tree[i] += delta;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Record a new measured (or estimated) size for item `pos`.
+    pub(crate) fn set(&mut self, pos: usize, new_size: f64) {
+        let delta = new_size - self.sizes[pos];
+        self.sizes[pos] = new_size;
+        self.add_to_tree(pos, delta);
+    }
+
+    /// The number of items currently tracked.
+    pub(crate) fn len(&self) -> usize { self.sizes.len() }
+
+    /// The current size of item `pos`.
+    pub(crate) fn get(&self, pos: usize) -> f64 { self.sizes[pos] }
+
+    /// Cumulative size of all items before `idx`.
+    pub(crate) fn prefix_sum(&self, idx: usize) -> f64 {
+        let mut i = idx;
+        let mut sum = 0.0;
+        while i > 0 {
+            sum += self.tree[i];
+            i -= i & i.wrapping_neg();
+        }
+        sum
+    }
+
+    /// Total size of all tracked items.
+    pub(crate) fn total(&self) -> f64 { self.prefix_sum(self.sizes.len()) }
+
+    /// Find the last item index `pos` whose cumulative offset (the sum of
+    /// all sizes before it) is `<= target`, returning `pos` and that offset.
+    ///
+    /// This is the standard Fenwick tree "lower_bound" descent: starting
+    /// from the highest power of two `<= len`, greedily take the largest
+    /// step that keeps the running prefix sum within `target`.
+    pub(crate) fn lower_bound(&self, target: f64) -> (usize, f64) {
+        let n = self.sizes.len();
+        let mut highest_bit = 0usize;
+        while (1usize << (highest_bit + 1)) <= n {
+            highest_bit += 1;
+        }
+        let mut bit = if n == 0 { 0 } else { 1usize << highest_bit };
+
+        let mut pos = 0usize;
+        let mut offset = 0.0;
+        while bit > 0 {
+            let next = pos + bit;
+            if next <= n && offset + self.tree[next] <= target {
+                pos = next;
+                offset += self.tree[pos];
+            }
+            bit >>= 1;
+        }
+        (pos, offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OffsetIndex;
+
+    #[test]
+    fn empty_index_has_no_total_and_clamps_lower_bound() {
+        let index = OffsetIndex::default();
+        assert_eq!(index.len(), 0);
+        assert_eq!(index.total(), 0.0);
+        assert_eq!(index.lower_bound(0.0), (0, 0.0));
+        assert_eq!(index.lower_bound(100.0), (0, 0.0));
+    }
+
+    #[test]
+    fn single_item() {
+        let mut index = OffsetIndex::default();
+        index.resize(1, 10.0);
+        assert_eq!(index.len(), 1);
+        assert_eq!(index.get(0), 10.0);
+        assert_eq!(index.total(), 10.0);
+        // Still within the one item.
+        assert_eq!(index.lower_bound(5.0), (0, 0.0));
+        // Past the one item: lands on the past-the-end sentinel index.
+        assert_eq!(index.lower_bound(10.0), (1, 10.0));
+    }
+
+    #[test]
+    fn set_updates_total_and_prefix_sum() {
+        let mut index = OffsetIndex::default();
+        index.resize(3, 10.0);
+        index.set(1, 25.0);
+        assert_eq!(index.get(1), 25.0);
+        assert_eq!(index.total(), 10.0 + 25.0 + 10.0);
+        assert_eq!(index.prefix_sum(2), 10.0 + 25.0);
+    }
+
+    #[test]
+    fn growth_after_shrink_rebuilds_cumulative_sums() {
+        let mut index = OffsetIndex::default();
+        index.resize(4, 1.0);
+        index.set(0, 10.0);
+        index.set(1, 20.0);
+        index.set(2, 30.0);
+        index.set(3, 40.0);
+        assert_eq!(index.total(), 100.0);
+
+        // Shrinking discards the truncated items' contributions.
+        index.resize(1, 0.0);
+        assert_eq!(index.total(), 10.0);
+
+        // Growing back must roll the earlier items back into the higher
+        // Fenwick nodes exposed by the larger size, not just graft the new
+        // entries onto the old (now too-short) tree.
+        index.resize(4, 5.0);
+        assert_eq!(index.len(), 4);
+        assert_eq!(index.get(0), 10.0);
+        assert_eq!(index.get(1), 5.0);
+        assert_eq!(index.get(2), 5.0);
+        assert_eq!(index.get(3), 5.0);
+        assert_eq!(index.total(), 10.0 + 5.0 + 5.0 + 5.0);
+        assert_eq!(index.prefix_sum(2), 10.0 + 5.0);
+    }
+
+    #[test]
+    fn lower_bound_at_and_past_item_boundaries() {
+        let mut index = OffsetIndex::default();
+        index.resize(4, 1.0);
+        index.set(0, 10.0);
+        index.set(1, 20.0);
+        index.set(2, 30.0);
+        index.set(3, 40.0);
+        // Cumulative offsets: item 0 @ 0, item 1 @ 10, item 2 @ 30, item 3 @ 60, total 100.
+
+        assert_eq!(index.lower_bound(0.0), (0, 0.0));
+        assert_eq!(index.lower_bound(9.0), (0, 0.0));
+        // Exactly at item 1's boundary: the boundary belongs to item 1.
+        assert_eq!(index.lower_bound(10.0), (1, 10.0));
+        assert_eq!(index.lower_bound(59.0), (2, 30.0));
+        assert_eq!(index.lower_bound(60.0), (3, 60.0));
+        // At (and beyond) the total, lands on the past-the-end sentinel index.
+        assert_eq!(index.lower_bound(100.0), (4, 100.0));
+        assert_eq!(index.lower_bound(1000.0), (4, 100.0));
+    }
+}